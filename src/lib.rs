@@ -88,6 +88,115 @@ impl<T> Cd<T> {
     pub fn mutate_silently(&mut self) -> &mut T {
         &mut self.data
     }
+
+    /// Project the Cd down to one of its fields, returning a [`CdProj`] that
+    /// shares this Cd's `changed` flag.
+    ///
+    /// Mutating the field through the returned guard's `DerefMut` trips
+    /// change detection on `self`, but reading it does not.
+    ///
+    /// ```
+    /// use changed::Cd;
+    /// let mut cd = Cd::new((1, 2));
+    /// *cd.map_unchanged(|t| &mut t.0) += 1;
+    /// assert!(cd.changed());
+    /// assert_eq!(*cd, (2, 2));
+    /// ```
+    pub fn map_unchanged<U: ?Sized, F: FnOnce(&mut T) -> &mut U>(&mut self, f: F) -> CdProj<'_, U> {
+        CdProj {
+            data: f(&mut self.data),
+            changed: &mut self.changed,
+        }
+    }
+}
+
+impl<T: DerefMut> Cd<T> {
+    /// Project the Cd onto its `Deref` target, returning a [`CdProj`] that
+    /// shares this Cd's `changed` flag.
+    ///
+    /// Unlike [`deref_mut()`](DerefMut::deref_mut), obtaining the projection
+    /// does not itself trip change detection; only mutating through the
+    /// returned guard does.
+    ///
+    /// ```
+    /// use changed::Cd;
+    /// let mut cd = Cd::new(Box::new(5));
+    /// assert_eq!(*cd.as_deref_mut(), 5);
+    /// assert!(!cd.changed());
+    /// *cd.as_deref_mut() = 6;
+    /// assert!(cd.changed());
+    /// ```
+    pub fn as_deref_mut(&mut self) -> CdProj<'_, T::Target> {
+        self.map_unchanged(|data| &mut **data)
+    }
+}
+
+/// A projection of a [`Cd`] onto one of its fields, obtained from
+/// [`Cd::map_unchanged()`].
+///
+/// Mutating the projected value through `DerefMut` trips change detection
+/// on the [`Cd`] it was projected from.
+pub struct CdProj<'a, T: ?Sized> {
+    data: &'a mut T,
+    changed: &'a mut bool,
+}
+
+impl<T: ?Sized> CdProj<'_, T> {
+    /// Check whether the parent Cd has been flagged as changed.
+    pub fn changed(&self) -> bool {
+        *self.changed
+    }
+
+    /// Mutate the projection without tripping change detection on the parent.
+    pub fn mutate_silently(&mut self) -> &mut T {
+        self.data
+    }
+}
+
+impl<T: PartialEq> CdProj<'_, T> {
+    /// Set the value, but only trip change detection on the parent if it
+    /// actually differs.
+    ///
+    /// Returns whether the value was changed.
+    pub fn set_if_neq(&mut self, value: T) -> bool {
+        if *self.data != value {
+            *self.data = value;
+            *self.changed = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replace the value, but only trip change detection on the parent if it
+    /// actually differs.
+    ///
+    /// Returns the old value if it was replaced, `None` otherwise.
+    pub fn replace_if_neq(&mut self, value: T) -> Option<T> {
+        if *self.data != value {
+            *self.changed = true;
+            Some(std::mem::replace(self.data, value))
+        } else {
+            None
+        }
+    }
+}
+
+/// `deref()` does not trip change detection.
+impl<T: ?Sized> Deref for CdProj<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.data
+    }
+}
+
+/// `deref_mut()` trips change detection on the parent Cd.
+impl<T: ?Sized> DerefMut for CdProj<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        *self.changed = true;
+        self.data
+    }
 }
 
 /// `deref()` does not trip change detection.
@@ -129,9 +238,219 @@ impl<T: PartialEq> PartialEq<T> for Cd<T> {
     }
 }
 
+impl<T: PartialEq> Cd<T> {
+    /// Set the value, but only trip change detection if it actually differs.
+    ///
+    /// Returns whether the value was changed.
+    ///
+    /// ```
+    /// use changed::Cd;
+    /// let mut cd = Cd::new(5);
+    /// assert!(!cd.set_if_neq(5));
+    /// assert!(!cd.changed());
+    /// assert!(cd.set_if_neq(6));
+    /// assert!(cd.changed());
+    /// ```
+    pub fn set_if_neq(&mut self, value: T) -> bool {
+        if self.data != value {
+            self.data = value;
+            self.changed = true;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replace the value, but only trip change detection if it actually differs.
+    ///
+    /// Returns the old value if it was replaced, `None` otherwise.
+    ///
+    /// ```
+    /// use changed::Cd;
+    /// let mut cd = Cd::new(5);
+    /// assert_eq!(cd.replace_if_neq(5), None);
+    /// assert!(!cd.changed());
+    /// assert_eq!(cd.replace_if_neq(6), Some(5));
+    /// assert!(cd.changed());
+    /// ```
+    pub fn replace_if_neq(&mut self, value: T) -> Option<T> {
+        if self.data != value {
+            self.changed = true;
+            Some(std::mem::replace(&mut self.data, value))
+        } else {
+            None
+        }
+    }
+}
+
+/// Like [`Cd`], but change detection is decided by a user-supplied predicate
+/// instead of by tracking `deref_mut()` calls.
+///
+/// Start by creating one with [`new()`](CdFn::new()).
+pub struct CdFn<T, F: Fn(&T, &T) -> bool> {
+    data: T,
+    baseline: T,
+    changed_fn: F,
+}
+
+impl<T: Clone, F: Fn(&T, &T) -> bool> CdFn<T, F> {
+    /// Create a new CdFn with data and a change predicate.
+    ///
+    /// The predicate is called with the value as of the last [`reset()`](CdFn::reset())
+    /// (or creation) and the current value, and should return whether that counts
+    /// as a change.
+    ///
+    /// ```
+    /// use changed::CdFn;
+    /// let cd = CdFn::new(5, |old: &i32, new: &i32| old != new);
+    /// assert!(!cd.changed());
+    /// ```
+    pub fn new(data: T, changed_fn: F) -> CdFn<T, F> {
+        let baseline = data.clone();
+        CdFn {
+            data,
+            baseline,
+            changed_fn,
+        }
+    }
+
+    /// Reset the change tracking baseline to the current value.
+    /// ```
+    /// use changed::CdFn;
+    /// let mut cd = CdFn::new(5, |old: &i32, new: &i32| old != new);
+    /// *cd += 5;
+    /// assert!(cd.changed());
+    /// cd.reset();
+    /// assert!(!cd.changed());
+    /// ```
+    pub fn reset(&mut self) {
+        self.baseline = self.data.clone();
+    }
+
+    /// Check whether the change predicate considers the current value changed
+    /// from the baseline recorded at the last [`reset()`](CdFn::reset()) (or creation).
+    /// ```
+    /// use changed::CdFn;
+    /// let mut cd = CdFn::new(5, |old: &i32, new: &i32| (old - new).abs() > 1);
+    /// *cd += 1;
+    /// assert!(!cd.changed()); // within the epsilon, not considered a change
+    /// *cd += 1;
+    /// assert!(cd.changed());
+    /// ```
+    pub fn changed(&self) -> bool {
+        (self.changed_fn)(&self.baseline, &self.data)
+    }
+
+    /// Take the data out of the CdFn.
+    /// Consumes self and returns data.
+    pub fn take(self) -> T {
+        self.data
+    }
+
+    /// Mutate the CdFn without tripping change detection.
+    ///
+    /// Unlike [`Cd::mutate_silently()`], this takes the mutation as a
+    /// closure: since a CdFn computes `changed()` from the baseline rather
+    /// than from a flag, the baseline has to be re-synced to the new value
+    /// once the mutation is done.
+    /// ```
+    /// use changed::CdFn;
+    /// let mut cd = CdFn::new(5, |old: &i32, new: &i32| old != new);
+    /// cd.mutate_silently(|data| *data += 5);
+    /// assert!(!cd.changed());
+    /// ```
+    pub fn mutate_silently(&mut self, f: impl FnOnce(&mut T)) {
+        f(&mut self.data);
+        self.baseline = self.data.clone();
+    }
+}
+
+/// `deref()` does not affect change detection.
+impl<T, F: Fn(&T, &T) -> bool> Deref for CdFn<T, F> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+/// `deref_mut()` does not itself trip change detection; whether a change is
+/// reported depends on the change predicate when [`changed()`](CdFn::changed()) is called.
+impl<T, F: Fn(&T, &T) -> bool> DerefMut for CdFn<T, F> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+/// A "hanging-get" style observer built on top of [`Cd`].
+///
+/// A [`Watcher`] lets a single caller park a callback and be notified, at
+/// most once, the next time the watched value meaningfully changes, rather
+/// than polling [`Cd::changed()`] every frame.
+pub struct Watcher<T> {
+    cd: Cd<T>,
+    last_sent: T,
+    changed_fn: ChangeFn<T>,
+    waiting: Option<Responder<T>>,
+}
+
+type ChangeFn<T> = Box<dyn Fn(&T, &T) -> bool>;
+type Responder<T> = Box<dyn FnOnce(&T)>;
+
+impl<T: Clone + PartialEq> Watcher<T> {
+    /// Create a new Watcher, using equality to decide whether a change is
+    /// meaningful.
+    pub fn new(data: T) -> Watcher<T> {
+        Watcher::with_change_fn(data, |old, new| old != new)
+    }
+}
+
+impl<T: Clone> Watcher<T> {
+    /// Create a new Watcher with a custom change predicate, deciding whether
+    /// a new value differs enough from the last one delivered to a responder
+    /// to be worth notifying about.
+    pub fn with_change_fn(data: T, changed_fn: impl Fn(&T, &T) -> bool + 'static) -> Watcher<T> {
+        Watcher {
+            last_sent: data.clone(),
+            cd: Cd::new(data),
+            changed_fn: Box::new(changed_fn),
+            waiting: None,
+        }
+    }
+
+    /// Park a responder to be called with the current value the next time
+    /// it meaningfully changes.
+    ///
+    /// Only one responder can be parked at a time; registering a new one
+    /// replaces any responder that has not yet been notified.
+    pub fn watch(&mut self, responder: impl FnOnce(&T) + 'static) {
+        self.waiting = Some(Box::new(responder));
+    }
+
+    /// Mutate the watched value through `f`, then, if it changed, notify the
+    /// parked responder (if any) and reset change detection.
+    ///
+    /// The responder only fires when the change predicate considers the new
+    /// value meaningfully different from the last one delivered.
+    pub fn update(&mut self, f: impl FnOnce(&mut Cd<T>)) {
+        f(&mut self.cd);
+
+        if self.cd.changed() {
+            if (self.changed_fn)(&self.last_sent, &self.cd) {
+                if let Some(responder) = self.waiting.take() {
+                    responder(&self.cd);
+                    self.last_sent = (*self.cd).clone();
+                }
+            }
+
+            self.cd.reset();
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::Cd;
+    use crate::{Cd, CdFn, Watcher};
 
     #[test]
     fn it_works() {
@@ -142,4 +461,83 @@ mod tests {
         assert_eq!(*changed, 20);
         assert!(!changed.changed);
     }
+
+    #[test]
+    fn set_if_neq_ignores_equal_values() {
+        let mut cd = Cd::new(5);
+        assert!(!cd.set_if_neq(5));
+        assert!(!cd.changed());
+        assert!(cd.set_if_neq(6));
+        assert!(cd.changed());
+        assert_eq!(*cd, 6);
+    }
+
+    #[test]
+    fn replace_if_neq_ignores_equal_values() {
+        let mut cd = Cd::new(5);
+        assert_eq!(cd.replace_if_neq(5), None);
+        assert!(!cd.changed());
+        assert_eq!(cd.replace_if_neq(6), Some(5));
+        assert!(cd.changed());
+        assert_eq!(*cd, 6);
+    }
+
+    #[test]
+    fn cd_fn_uses_predicate() {
+        let mut cd = CdFn::new(5.0, |old: &f64, new: &f64| (old - new).abs() > 1.0);
+        *cd += 0.5;
+        assert!(!cd.changed());
+        *cd += 0.6;
+        assert!(cd.changed());
+        cd.reset();
+        assert!(!cd.changed());
+    }
+
+    #[test]
+    fn map_unchanged_projects_field() {
+        let mut cd = Cd::new((1, 2));
+        assert_eq!(*cd.map_unchanged(|t| &mut t.0), 1);
+        assert!(!cd.changed());
+
+        *cd.map_unchanged(|t| &mut t.1) += 5;
+        assert!(cd.changed());
+        assert_eq!(*cd, (1, 7));
+    }
+
+    #[test]
+    fn as_deref_mut_only_trips_on_mutation() {
+        let mut cd = Cd::new(Box::new(5));
+        assert_eq!(*cd.as_deref_mut(), 5);
+        assert!(!cd.changed());
+
+        assert!(!cd.as_deref_mut().set_if_neq(5));
+        assert!(!cd.changed());
+
+        assert!(cd.as_deref_mut().set_if_neq(6));
+        assert!(cd.changed());
+        assert_eq!(*cd, Box::new(6));
+    }
+
+    #[test]
+    fn watcher_notifies_parked_responder_on_change() {
+        use std::cell::RefCell;
+        use std::rc::Rc;
+
+        let mut watcher = Watcher::new(5);
+        let received = Rc::new(RefCell::new(None));
+
+        let received_clone = received.clone();
+        watcher.watch(move |v| *received_clone.borrow_mut() = Some(*v));
+
+        watcher.update(|cd| *cd.mutate_silently() = 5); // no deref_mut, no change
+        assert_eq!(*received.borrow(), None);
+
+        watcher.update(|cd| **cd += 1);
+        assert_eq!(*received.borrow(), Some(6));
+
+        // No responder parked, so a further change is not delivered anywhere,
+        // but the watcher still remembers it was notified of 6 last.
+        watcher.update(|cd| **cd += 1);
+        assert_eq!(*received.borrow(), Some(6));
+    }
 }